@@ -5,10 +5,147 @@
 /// For a space that is empty or unpopulated
 ///     Each cell with three neighbors becomes populated.
 use anyhow::{bail, Result};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::ops::Add;
 
-#[derive(Debug, Clone, Copy)]
+/// A Life-like cellular automaton rule, expressed as the sets of live-neighbour
+/// counts that trigger a birth or allow survival.
+///
+/// Parsed from a standard rulestring such as `"B3/S23"` (Conway's Game of Life)
+/// or `"B36/S23"` (HighLife).
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    birth: HashSet<u8>,
+    survival: HashSet<u8>,
+}
+
+impl Rule {
+    /// Parses a rulestring of the form `"B<digits>/S<digits>"`.
+    fn parse(rulestring: &str) -> Result<Self> {
+        let (birth_part, survival_part) = rulestring
+            .split_once('/')
+            .ok_or_else(|| anyhow::anyhow!("Rulestring must contain a '/' separator."))?;
+
+        let birth_digits = birth_part
+            .strip_prefix('B')
+            .ok_or_else(|| anyhow::anyhow!("Rulestring must start with 'B'."))?;
+        let survival_digits = survival_part
+            .strip_prefix('S')
+            .ok_or_else(|| anyhow::anyhow!("Rulestring survival part must start with 'S'."))?;
+
+        Ok(Self {
+            birth: Self::parse_digits(birth_digits)?,
+            survival: Self::parse_digits(survival_digits)?,
+        })
+    }
+
+    fn parse_digits(digits: &str) -> Result<HashSet<u8>> {
+        digits
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|d| d as u8)
+                    .ok_or_else(|| anyhow::anyhow!("'{c}' is not a valid neighbour count."))
+            })
+            .collect()
+    }
+
+    /// Formats the rule back into a rulestring of the form `"B<digits>/S<digits>"`,
+    /// with neighbour counts sorted ascending.
+    fn to_rulestring(&self) -> String {
+        format!(
+            "B{}/S{}",
+            Self::format_digits(&self.birth),
+            Self::format_digits(&self.survival)
+        )
+    }
+
+    fn format_digits(digits: &HashSet<u8>) -> String {
+        let mut sorted: Vec<u8> = digits.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted.iter().map(|d| d.to_string()).collect()
+    }
+
+    /// Whether a cell with `live_neighbours` neighbours is populated in the next generation.
+    fn next_state(&self, cell: Cell, live_neighbours: u8) -> Cell {
+        let alive = if cell.is_populated() {
+            self.survival.contains(&live_neighbours)
+        } else {
+            self.birth.contains(&live_neighbours)
+        };
+
+        if alive {
+            Cell::Populated
+        } else {
+            Cell::Empty
+        }
+    }
+}
+
+impl Default for Rule {
+    /// Conway's Game of Life: `B3/S23`.
+    fn default() -> Self {
+        Self::parse("B3/S23").expect("the Conway rulestring is valid")
+    }
+}
+
+/// The 8 Moore-neighbourhood directions, as `(dx, dy)` steps.
+const DIRECTIONS: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Wraps `value + delta` into `0..size`.
+fn wrap(value: u16, delta: i32, size: u16) -> u16 {
+    let size = size as i32;
+    let wrapped = (value as i32 + delta).rem_euclid(size);
+    wrapped as u16
+}
+
+/// The glyphs used to render populated and empty cells as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Renderer {
+    alive: char,
+    dead: char,
+}
+
+impl Renderer {
+    fn new(alive: char, dead: char) -> Self {
+        Self { alive, dead }
+    }
+}
+
+impl Default for Renderer {
+    /// Populated cells render as `'O'`, empty cells as `'.'`.
+    fn default() -> Self {
+        Self::new('O', '.')
+    }
+}
+
+/// How a `Grid` gathers a cell's neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Topology {
+    /// Off-edge neighbours are simply absent.
+    Bounded,
+    /// Edges wrap around: a neighbour stepping past `width - 1` (or `0`) maps
+    /// back to `0` (or `width - 1`), using the grid's bounds for the modulo.
+    Toroidal,
+    /// For each of the 8 compass directions, walk outward one step at a time,
+    /// skipping empty cells, and count the first populated cell seen in that
+    /// direction; stops at the grid's edge without wrapping.
+    LineOfSight,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 enum Cell {
+    #[default]
     Empty,
     Populated,
 }
@@ -42,12 +179,6 @@ impl Cell {
     }
 }
 
-struct Grid {
-    /// Outer Vector represents columns, inner Vec represents rows
-    /// e.g. cells[x][y] returns the cell at column x, row y.
-    cells: Vec<Vec<Cell>>,
-}
-
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 struct Column(u16);
 
@@ -59,10 +190,6 @@ impl Column {
         Self(column)
     }
 
-    fn usize(&self) -> usize {
-        self.0 as usize
-    }
-
     fn try_sub(self, rh: u16) -> Option<Self> {
         let lh = self.0;
 
@@ -79,10 +206,6 @@ impl Row {
         Self(row)
     }
 
-    fn usize(&self) -> usize {
-        self.0 as usize
-    }
-
     fn try_sub(self, rh: u16) -> Option<Self> {
         let lh = self.0;
 
@@ -127,7 +250,6 @@ impl Coordinates {
     /// (i-1,j-1) (i,j-1)   (i+1,j-1)
     /// (i-1,j)   Self(i,j) (i+1,j)
     /// (i-1,j+1) (i,j+1)   (i+1,j+1)
-
     fn neighbours(&self) -> Vec<Coordinates> {
         let mut neighbours = vec![];
 
@@ -153,70 +275,761 @@ impl Coordinates {
     }
 }
 
-impl Grid {
-    fn new(columns: Column, rows: Row) -> Self {
-        let cells = vec![vec![Cell::default(); rows.usize()]; columns.usize()];
-        Self { cells }
+impl From<(Column, Row)> for Coordinates {
+    fn from((column, row): (Column, Row)) -> Self {
+        Coordinates::new(column, row)
     }
+}
 
-    fn cell(&self, column_index: Column, row_index: Row) -> Option<Cell> {
-        match self.cells.get(column_index.usize()) {
-            Some(row) => row.get(row_index.usize()).copied(),
-            None => None,
+/// An axis-aligned rectangle of grid coordinates: an origin `(x, y)` and a
+/// `width`/`height` extent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rect {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+impl Rect {
+    fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
         }
     }
 
-    fn populate(&mut self, column_index: Column, row_index: Row) -> Result<()> {
-        match self.cells.get_mut(column_index.usize()) {
-            Some(row) => match row.get_mut(row_index.usize()) {
-                Some(cell) => {
-                    cell.spawn();
-                    Ok(())
-                }
-                None => bail!("Coordinates are out of bound."),
-            },
+    fn contains(&self, coordinates: Coordinates) -> bool {
+        let column = coordinates.column.0;
+        let row = coordinates.row.0;
+
+        column >= self.x
+            && column < self.x + self.width
+            && row >= self.y
+            && row < self.y + self.height
+    }
+
+    /// Translates coordinates into this rectangle's row-major linear index,
+    /// or `None` if they fall outside it.
+    fn index_of(&self, coordinates: Coordinates) -> Option<usize> {
+        if !self.contains(coordinates) {
+            return None;
+        }
+
+        let local_column = (coordinates.column.0 - self.x) as usize;
+        let local_row = (coordinates.row.0 - self.y) as usize;
+        Some(local_row * self.width as usize + local_column)
+    }
+}
+
+/// Where a `Grid::resize` keeps its existing pattern anchored as the grid
+/// grows or shrinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor {
+    /// The origin `(x, y)` stays fixed; the grid grows to the right and down.
+    TopLeft,
+    /// The origin `(x, y)` stays fixed, and the existing pattern is shifted so
+    /// that it sits in the middle of the new bounds rather than at the origin.
+    Centered,
+}
+
+/// A grid of cells of type `T`, stored row-major in a single `Vec<T>` and
+/// addressed through an explicit `Rect` of bounds.
+#[derive(Debug, Clone)]
+struct Grid<T> {
+    cells: Vec<T>,
+    bounds: Rect,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid spanning `bounds`, seeding every cell from `generator`.
+    fn with_generator(bounds: Rect, mut generator: impl FnMut(Coordinates) -> T) -> Self {
+        let mut cells = Vec::with_capacity(bounds.width as usize * bounds.height as usize);
+
+        for row_offset in 0..bounds.height {
+            for column_offset in 0..bounds.width {
+                let coordinates = Coordinates::new(
+                    Column::new(bounds.x + column_offset),
+                    Row::new(bounds.y + row_offset),
+                );
+                cells.push(generator(coordinates));
+            }
+        }
+
+        Self { cells, bounds }
+    }
+
+    fn get(&self, coordinates: impl Into<Coordinates>) -> Option<&T> {
+        self.bounds
+            .index_of(coordinates.into())
+            .map(|index| &self.cells[index])
+    }
+
+    fn set(&mut self, coordinates: impl Into<Coordinates>, value: T) -> Result<()> {
+        match self.bounds.index_of(coordinates.into()) {
+            Some(index) => {
+                self.cells[index] = value;
+                Ok(())
+            }
             None => bail!("Coordinates are out of bound."),
         }
     }
+}
 
-    fn neighbour_cells(&self, column_index: Column, row_index: Row) -> Vec<Cell> {
-        let coordinates = Coordinates::new(column_index, row_index).neighbours();
-        let mut neighbours = vec![];
+impl<T: Clone + Default> Grid<T> {
+    /// Reallocates the grid to `new_columns` by `new_rows`, re-anchoring the
+    /// existing pattern per `anchor`. Cells that still fall inside the new
+    /// bounds keep their state; newly exposed cells default to `T::default()`;
+    /// cells outside a shrunk grid are dropped.
+    fn resize(&mut self, new_columns: Column, new_rows: Row, anchor: Anchor) {
+        let old_bounds = self.bounds;
+        let new_width = new_columns.0;
+        let new_height = new_rows.0;
 
-        for coordinates in coordinates {
-            if let Some(cell) = self.cell(coordinates.column, coordinates.row) {
-                neighbours.push(cell)
+        // How far an old local coordinate must shift to land at the same
+        // local coordinate in the new bounds.
+        let (offset_x, offset_y) = match anchor {
+            Anchor::TopLeft => (0, 0),
+            Anchor::Centered => (
+                (new_width as i32 - old_bounds.width as i32) / 2,
+                (new_height as i32 - old_bounds.height as i32) / 2,
+            ),
+        };
+
+        let new_bounds = Rect::new(old_bounds.x, old_bounds.y, new_width, new_height);
+        let mut new_cells = Vec::with_capacity(new_width as usize * new_height as usize);
+
+        for new_row_offset in 0..new_height {
+            for new_column_offset in 0..new_width {
+                let old_column = new_column_offset as i32 - offset_x;
+                let old_row = new_row_offset as i32 - offset_y;
+
+                let value = if old_column >= 0 && old_row >= 0 {
+                    let old_coordinates = Coordinates::new(
+                        Column::new(old_bounds.x + old_column as u16),
+                        Row::new(old_bounds.y + old_row as u16),
+                    );
+                    self.get(old_coordinates).cloned()
+                } else {
+                    None
+                }
+                .unwrap_or_default();
+
+                new_cells.push(value);
             }
         }
 
-        neighbours
+        self.cells = new_cells;
+        self.bounds = new_bounds;
+    }
+}
+
+impl Grid<Cell> {
+    fn new(columns: Column, rows: Row) -> Self {
+        Self::with_generator(Rect::new(0, 0, columns.0, rows.0), |_| Cell::default())
     }
 
+    fn cell(&self, column_index: Column, row_index: Row) -> Option<Cell> {
+        self.get((column_index, row_index)).copied()
+    }
+
+    fn populate(&mut self, column_index: Column, row_index: Row) -> Result<()> {
+        self.set((column_index, row_index), Cell::Populated)
+    }
+
+    /// Counts this cell's live neighbours under `topology`, against the
+    /// grid's current state.
+    fn neighbour_count(&self, column_index: Column, row_index: Row, topology: Topology) -> u8 {
+        Self::live_neighbour_count(
+            &self.cells,
+            self.bounds,
+            Coordinates::new(column_index, row_index),
+            topology,
+        )
+    }
+
+    /// Advances the grid by one generation under Conway's classic rule (`B3/S23`)
+    /// and the bounded neighbourhood.
     fn next(&mut self) {
-        for i in 0..self.cells.len() {
-            // TODO: have a safe method on grid
-            for j in 0..self.cells[i].len() {
-                let column = Column::new(i as u16);
-                let row = Row::new(j as u16);
-                let neighbours = self.neighbour_cells(column, row);
-                let mut populated = 0;
-                for cell in neighbours {
-                    if cell.is_populated() {
-                        populated += 1;
+        self.next_with(&Rule::default())
+    }
+
+    /// Advances the grid by one generation under the given `Rule`, using the
+    /// bounded neighbourhood (off-edge neighbours are simply absent).
+    fn next_with(&mut self, rule: &Rule) {
+        self.next_with_topology(rule, Topology::Bounded)
+    }
+
+    /// Advances the grid by one generation under the given `Rule` and `Topology`.
+    ///
+    /// Every cell's next state is computed from a snapshot of the current
+    /// generation, so neighbour counts are never read from already-updated cells.
+    fn next_with_topology(&mut self, rule: &Rule, topology: Topology) {
+        let previous = self.cells.clone();
+        let bounds = self.bounds;
+
+        for row_offset in 0..bounds.height {
+            for column_offset in 0..bounds.width {
+                let coordinates = Coordinates::new(
+                    Column::new(bounds.x + column_offset),
+                    Row::new(bounds.y + row_offset),
+                );
+                let index = bounds
+                    .index_of(coordinates)
+                    .expect("coordinates are within bounds");
+                let populated = Self::live_neighbour_count(&previous, bounds, coordinates, topology);
+
+                self.cells[index] = rule.next_state(previous[index], populated);
+            }
+        }
+    }
+
+    /// Counts populated neighbours of `coordinates` within `previous`, under `topology`.
+    fn live_neighbour_count(
+        previous: &[Cell],
+        bounds: Rect,
+        coordinates: Coordinates,
+        topology: Topology,
+    ) -> u8 {
+        let is_populated_at = |coordinates: Coordinates| {
+            bounds
+                .index_of(coordinates)
+                .is_some_and(|index| previous[index].is_populated())
+        };
+
+        match topology {
+            Topology::Bounded => DIRECTIONS
+                .iter()
+                .filter(|&&(dx, dy)| {
+                    let column = u16::try_from(coordinates.column.0 as i32 + dx);
+                    let row = u16::try_from(coordinates.row.0 as i32 + dy);
+                    match (column, row) {
+                        (Ok(column), Ok(row)) => {
+                            is_populated_at(Coordinates::new(Column::new(column), Row::new(row)))
+                        }
+                        _ => false,
+                    }
+                })
+                .count() as u8,
+            Topology::Toroidal => DIRECTIONS
+                .iter()
+                .filter(|&&(dx, dy)| {
+                    let wrapped = Coordinates::new(
+                        Column::new(wrap(coordinates.column.0, dx, bounds.width)),
+                        Row::new(wrap(coordinates.row.0, dy, bounds.height)),
+                    );
+                    is_populated_at(wrapped)
+                })
+                .count() as u8,
+            Topology::LineOfSight => DIRECTIONS
+                .iter()
+                .filter(|&&(dx, dy)| {
+                    let mut column = coordinates.column.0 as i32 + dx;
+                    let mut row = coordinates.row.0 as i32 + dy;
+
+                    loop {
+                        if column < bounds.x as i32
+                            || column >= (bounds.x + bounds.width) as i32
+                            || row < bounds.y as i32
+                            || row >= (bounds.y + bounds.height) as i32
+                        {
+                            return false;
+                        }
+
+                        let sighted = Coordinates::new(Column::new(column as u16), Row::new(row as u16));
+                        if is_populated_at(sighted) {
+                            return true;
+                        }
+
+                        column += dx;
+                        row += dy;
+                    }
+                })
+                .count() as u8,
+        }
+    }
+
+    /// Parses an RLE pattern and returns a new grid exactly large enough to
+    /// hold it, along with the rulestring embedded in its header (Conway's
+    /// `B3/S23` if none was given).
+    fn from_rle(input: &str) -> Result<(Self, Rule)> {
+        let pattern = rle::parse(input)?;
+        let (width, height) = Self::pattern_extent(&pattern.cells);
+        let mut grid = Self::new(Column::new(width), Row::new(height));
+
+        for coordinates in pattern.cells {
+            grid.populate(coordinates.column, coordinates.row)?;
+        }
+
+        Ok((grid, pattern.rule))
+    }
+
+    /// Overlays an RLE pattern onto this grid, anchored at `origin`, and
+    /// returns the rulestring embedded in its header.
+    fn stamp_rle(&mut self, input: &str, origin: Coordinates) -> Result<Rule> {
+        let pattern = rle::parse(input)?;
+
+        for coordinates in pattern.cells {
+            let column = Column::new(origin.column.0 + coordinates.column.0);
+            let row = Row::new(origin.row.0 + coordinates.row.0);
+            self.populate(column, row)?;
+        }
+
+        Ok(pattern.rule)
+    }
+
+    /// Serializes the grid's current populated cells to the RLE format,
+    /// embedding `rule` in the header.
+    fn to_rle(&self, rule: &Rule) -> String {
+        let bounds = self.bounds;
+        let populated: Vec<Coordinates> = (0..bounds.height)
+            .flat_map(|row_offset| {
+                (0..bounds.width).map(move |column_offset| {
+                    Coordinates::new(
+                        Column::new(bounds.x + column_offset),
+                        Row::new(bounds.y + row_offset),
+                    )
+                })
+            })
+            .filter(|coordinates| {
+                self.cell(coordinates.column, coordinates.row)
+                    .is_some_and(|cell| cell.is_populated())
+            })
+            .collect();
+
+        let header = format!(
+            "x = {}, y = {}, rule = {}\n",
+            bounds.width,
+            bounds.height,
+            rule.to_rulestring()
+        );
+        header + &rle::serialize(&populated, bounds.width, bounds.height)
+    }
+
+    /// Returns the smallest `(width, height)` that contains every coordinate.
+    fn pattern_extent(cells: &[Coordinates]) -> (u16, u16) {
+        let width = cells.iter().map(|c| c.column.0 + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|c| c.row.0 + 1).max().unwrap_or(0);
+        (width, height)
+    }
+
+    /// Renders every cell within `region` as text, one line per row, using
+    /// `renderer`'s glyphs. Cells outside the grid (e.g. a `region` larger
+    /// than the grid itself) render as dead.
+    fn render_region(&self, region: Rect, renderer: &Renderer) -> String {
+        let mut output = String::new();
+
+        for row_offset in 0..region.height {
+            for column_offset in 0..region.width {
+                let column = Column::new(region.x + column_offset);
+                let row = Row::new(region.y + row_offset);
+                let glyph = match self.cell(column, row) {
+                    Some(cell) if cell.is_populated() => renderer.alive,
+                    _ => renderer.dead,
+                };
+                output.push(glyph);
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Renders the whole grid as text, one line per row, using `renderer`'s glyphs.
+    fn render(&self, renderer: &Renderer) -> String {
+        self.render_region(self.bounds, renderer)
+    }
+
+    /// Renders the whole grid inside a box border, using the default
+    /// `Renderer` glyphs. Only the outer border is drawn; rows and columns are
+    /// not ruled off from one another.
+    fn to_table(&self) -> String {
+        let bounds = self.bounds;
+        let border = format!("+{}+\n", "-".repeat(bounds.width as usize));
+
+        let mut output = border.clone();
+        for line in self.render(&Renderer::default()).lines() {
+            output.push('|');
+            output.push_str(line);
+            output.push_str("|\n");
+        }
+        output.push_str(&border);
+
+        output
+    }
+}
+
+impl fmt::Display for Grid<Cell> {
+    /// Renders populated cells as `'O'` and empty cells as `'.'`, one line per row.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(&Renderer::default()))
+    }
+}
+
+/// Parsing and serialization for the Life RLE pattern format
+/// (<https://conwaylife.com/wiki/Run_Length_Encoded>).
+mod rle {
+    use super::{Column, Coordinates, Row, Rule};
+    use anyhow::{bail, Result};
+    use std::collections::HashSet;
+
+    /// A pattern decoded from RLE: the populated cells, relative to the
+    /// pattern's own top-left corner at `(0, 0)`, and its embedded rulestring.
+    pub struct Pattern {
+        pub cells: Vec<Coordinates>,
+        pub rule: Rule,
+    }
+
+    /// Parses an RLE document: an optional `#` comment block, an
+    /// `x = m, y = n, rule = B3/S23` header line, and a run-length-encoded
+    /// body where a decimal count prefixes a tag (`b` dead, `o` alive,
+    /// `$` end of row, `!` end of pattern), e.g. `3o$2bo$bo!`.
+    pub fn parse(input: &str) -> Result<Pattern> {
+        let mut rule = Rule::default();
+        let mut body = String::new();
+        let mut header_seen = false;
+
+        for line in input.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if !header_seen && line.starts_with('x') {
+                header_seen = true;
+                if let Some(rulestring) = parse_rulestring(line) {
+                    rule = Rule::parse(&rulestring)?;
+                }
+                continue;
+            }
+
+            body.push_str(line);
+        }
+
+        if !header_seen {
+            bail!("RLE input is missing its 'x = ..., y = ...' header line.");
+        }
+
+        Ok(Pattern {
+            cells: parse_body(&body)?,
+            rule,
+        })
+    }
+
+    fn parse_rulestring(header: &str) -> Option<String> {
+        let (_, rule_part) = header.split_once("rule")?;
+        let rulestring: String = rule_part
+            .trim_start_matches(|c: char| c == '=' || c.is_whitespace())
+            .chars()
+            .take_while(|c| !c.is_whitespace() && *c != ',')
+            .collect();
+
+        (!rulestring.is_empty()).then_some(rulestring)
+    }
+
+    fn parse_body(body: &str) -> Result<Vec<Coordinates>> {
+        let mut cells = vec![];
+        let mut column = 0u16;
+        let mut row = 0u16;
+        let mut count_digits = String::new();
+
+        for c in body.chars() {
+            match c {
+                '0'..='9' => count_digits.push(c),
+                'b' | 'o' | '$' => {
+                    let count: u16 = if count_digits.is_empty() {
+                        1
+                    } else {
+                        count_digits.parse()?
+                    };
+                    count_digits.clear();
+
+                    match c {
+                        'b' => column += count,
+                        'o' => {
+                            for _ in 0..count {
+                                cells.push(Coordinates::new(Column::new(column), Row::new(row)));
+                                column += 1;
+                            }
+                        }
+                        '$' => {
+                            row += count;
+                            column = 0;
+                        }
+                        _ => unreachable!(),
                     }
                 }
+                '!' => break,
+                _ => bail!("'{c}' is not a valid RLE tag."),
+            }
+        }
+
+        Ok(cells)
+    }
+
+    /// Serializes populated cells back into RLE body form (without the header line).
+    pub fn serialize(cells: &[Coordinates], width: u16, height: u16) -> String {
+        let populated: HashSet<(u16, u16)> =
+            cells.iter().map(|c| (c.column.0, c.row.0)).collect();
+
+        let mut output = String::new();
+
+        for row in 0..height {
+            let mut column = 0;
+            while column < width {
+                let alive = populated.contains(&(column, row));
+                let run_start = column;
+                while column < width && populated.contains(&(column, row)) == alive {
+                    column += 1;
+                }
 
-                if populated < 2 {
-                    self.cells[i][j].die()
+                let run_length = column - run_start;
+                if run_length > 1 {
+                    output.push_str(&run_length.to_string());
                 }
+                output.push(if alive { 'o' } else { 'b' });
+            }
+            if row + 1 < height {
+                output.push('$');
             }
         }
+        output.push('!');
+
+        output
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use super::super::Grid;
+
+        #[test]
+        fn can_parse_glider() {
+            let pattern = parse("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!").unwrap();
+
+            assert_eq!(pattern.cells.len(), 5);
+            assert!(pattern
+                .cells
+                .contains(&Coordinates::new(Column::new(1), Row::new(0))));
+            assert!(pattern
+                .cells
+                .contains(&Coordinates::new(Column::new(2), Row::new(1))));
+            assert!(pattern
+                .cells
+                .contains(&Coordinates::new(Column::new(0), Row::new(2))));
+            assert!(pattern
+                .cells
+                .contains(&Coordinates::new(Column::new(1), Row::new(2))));
+            assert!(pattern
+                .cells
+                .contains(&Coordinates::new(Column::new(2), Row::new(2))));
+        }
+
+        #[test]
+        fn rejects_input_missing_header() {
+            assert!(parse("bo$2bo$3o!").is_err());
+        }
+
+        #[test]
+        fn can_roundtrip_grid_through_rle() {
+            let glider = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+            let (grid, rule) = Grid::from_rle(glider).unwrap();
+
+            let (reparsed, reparsed_rule) = Grid::from_rle(&grid.to_rle(&rule)).unwrap();
+
+            assert_eq!(rule, reparsed_rule);
+            for row in 0..3 {
+                for column in 0..3 {
+                    let column = Column::new(column);
+                    let row = Row::new(row);
+                    assert_eq!(
+                        grid.cell(column, row).unwrap().is_populated(),
+                        reparsed.cell(column, row).unwrap().is_populated()
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn can_parse_highlife_rule_from_header() {
+            let (_, rule) = Grid::from_rle("x = 1, y = 1, rule = B36/S23\no!").unwrap();
+
+            assert_eq!(rule, Rule::parse("B36/S23").unwrap());
+        }
+
+        #[test]
+        fn can_stamp_pattern_at_non_zero_origin() {
+            let mut grid = Grid::new(Column::new(6), Row::new(6));
+            let origin = Coordinates::new(Column::new(3), Row::new(4));
+
+            grid.stamp_rle("x = 2, y = 1, rule = B3/S23\n2o!", origin)
+                .unwrap();
+
+            assert!(grid.cell(Column::new(3), Row::new(4)).unwrap().is_populated());
+            assert!(grid.cell(Column::new(4), Row::new(4)).unwrap().is_populated());
+            assert!(grid.cell(Column::new(0), Row::new(0)).unwrap().is_empty());
+        }
     }
 }
 
-impl Default for Cell {
-    fn default() -> Self {
-        Cell::Empty
+/// A pair of signed coordinates on the infinite plane used by `SparseGrid`.
+///
+/// Unlike `Coordinates`, which is anchored at `(0, 0)` and clips at the
+/// grid's edges, `Coord` has no bounds: a pattern such as a glider can drift
+/// arbitrarily far in any direction without clipping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+impl Coord {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns all 8 Moore neighbours, unconditionally (there is no edge to clip against).
+    fn neighbours(&self) -> [Coord; 8] {
+        [
+            Coord::new(self.x - 1, self.y - 1),
+            Coord::new(self.x, self.y - 1),
+            Coord::new(self.x + 1, self.y - 1),
+            Coord::new(self.x - 1, self.y),
+            Coord::new(self.x + 1, self.y),
+            Coord::new(self.x - 1, self.y + 1),
+            Coord::new(self.x, self.y + 1),
+            Coord::new(self.x + 1, self.y + 1),
+        ]
+    }
+}
+
+/// An unbounded Game of Life grid that stores only populated cells.
+///
+/// Because dead cells are never materialised, the universe is effectively
+/// infinite in every direction and the cost of a generation scales with the
+/// live population rather than with the area of a fixed-size grid.
+#[derive(Debug, Clone, Default)]
+struct SparseGrid {
+    live_cells: HashSet<Coord>,
+}
+
+impl SparseGrid {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn populate(&mut self, coord: Coord) {
+        self.live_cells.insert(coord);
+    }
+
+    fn is_populated(&self, coord: Coord) -> bool {
+        self.live_cells.contains(&coord)
+    }
+
+    fn live_count(&self) -> usize {
+        self.live_cells.len()
+    }
+
+    /// Returns the `(min, max)` coordinates spanning every populated cell,
+    /// or `None` if the grid is empty.
+    fn bounding_box(&self) -> Option<(Coord, Coord)> {
+        let mut cells = self.live_cells.iter();
+        let first = cells.next()?;
+        let (mut min, mut max) = (*first, *first);
+
+        for cell in cells {
+            min.x = min.x.min(cell.x);
+            min.y = min.y.min(cell.y);
+            max.x = max.x.max(cell.x);
+            max.y = max.y.max(cell.y);
+        }
+
+        Some((min, max))
+    }
+
+    /// Advances the grid by one generation under Conway's classic rule (`B3/S23`).
+    fn next(&mut self) {
+        self.next_with(&Rule::default())
+    }
+
+    /// Advances the grid by one generation under the given `Rule`.
+    ///
+    /// Only cells that could possibly change are examined: every live cell and
+    /// its 8 neighbours. Tallying how many times each of those candidate
+    /// coordinates is named as a neighbour of a live cell gives its
+    /// live-neighbour count in a single pass, without ever visiting a dead
+    /// cell that has no live neighbours at all. Every live cell is also
+    /// seeded into the tally with a count of zero, so an isolated live cell
+    /// (no live neighbours) is still evaluated against `rule` instead of
+    /// being skipped outright — relevant for a `Rule` that survives on zero
+    /// neighbours. A birth on zero neighbours never happens, since such dead
+    /// cells are not visited.
+    fn next_with(&mut self, rule: &Rule) {
+        let mut live_neighbour_counts: HashMap<Coord, u8> = HashMap::new();
+
+        for &live_cell in &self.live_cells {
+            live_neighbour_counts.entry(live_cell).or_insert(0);
+            for neighbour in live_cell.neighbours() {
+                *live_neighbour_counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let mut next_generation = HashSet::new();
+
+        for (&coord, &count) in &live_neighbour_counts {
+            let cell = if self.is_populated(coord) {
+                Cell::Populated
+            } else {
+                Cell::Empty
+            };
+
+            if rule.next_state(cell, count).is_populated() {
+                next_generation.insert(coord);
+            }
+        }
+
+        self.live_cells = next_generation;
+    }
+
+    /// Renders the populated cells within `region` (given as `(min, max)`
+    /// coordinates, inclusive) as text, one line per row.
+    fn render_region(&self, region: (Coord, Coord), renderer: &Renderer) -> String {
+        let (min, max) = region;
+        let mut output = String::new();
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let glyph = if self.is_populated(Coord::new(x, y)) {
+                    renderer.alive
+                } else {
+                    renderer.dead
+                };
+                output.push(glyph);
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Renders every populated cell, bounded by `bounding_box`. An empty
+    /// grid renders as an empty string.
+    fn render(&self, renderer: &Renderer) -> String {
+        match self.bounding_box() {
+            Some(region) => self.render_region(region, renderer),
+            None => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for SparseGrid {
+    /// Renders populated cells as `'O'` and empty cells as `'.'`, one line
+    /// per row, bounded by the grid's `bounding_box`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(&Renderer::default()))
     }
 }
 
@@ -298,6 +1111,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_bounds_when_with_generator_then_each_coordinate_is_seeded() {
+        let bounds = Rect::new(0, 0, 3, 2);
+
+        let grid = Grid::with_generator(bounds, |coordinates| {
+            (coordinates.column.0 + coordinates.row.0) % 2 == 0
+        });
+
+        assert_eq!(grid.get((Column::new(0), Row::new(0))), Some(&true));
+        assert_eq!(grid.get((Column::new(1), Row::new(0))), Some(&false));
+        assert_eq!(grid.get((Column::new(2), Row::new(1))), Some(&false));
+    }
+
+    #[test]
+    fn given_grid_when_coordinates_out_of_bounds_then_get_returns_none_and_set_fails() {
+        let mut grid = Grid::new(Column::new(2), Row::new(2));
+
+        assert!(grid.get((Column::new(5), Row::new(5))).is_none());
+        assert!(grid.set((Column::new(5), Row::new(5)), Cell::Populated).is_err());
+    }
+
+    #[test]
+    fn given_grid_when_resized_top_left_then_pattern_keeps_its_position() {
+        let mut grid = Grid::new(Column::new(3), Row::new(3));
+        grid.populate(Column::new(1), Row::new(1)).unwrap();
+
+        grid.resize(Column::new(5), Row::new(5), Anchor::TopLeft);
+
+        assert!(grid.cell(Column::new(1), Row::new(1)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(4), Row::new(4)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn given_grid_when_resized_centered_then_pattern_is_recentred() {
+        let mut grid = Grid::new(Column::new(3), Row::new(3));
+        grid.populate(Column::new(1), Row::new(1)).unwrap();
+
+        grid.resize(Column::new(5), Row::new(5), Anchor::Centered);
+
+        // The old centre (1, 1) is now (2, 2) in the larger, re-centred grid.
+        assert!(grid.cell(Column::new(2), Row::new(2)).unwrap().is_populated());
+    }
+
+    #[test]
+    fn given_grid_when_shrunk_then_cells_outside_new_bounds_are_dropped() {
+        let mut grid = Grid::new(Column::new(5), Row::new(5));
+        grid.populate(Column::new(4), Row::new(4)).unwrap();
+        grid.populate(Column::new(1), Row::new(1)).unwrap();
+
+        grid.resize(Column::new(3), Row::new(3), Anchor::TopLeft);
+
+        assert!(grid.cell(Column::new(1), Row::new(1)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(4), Row::new(4)).is_none());
+    }
+
+    #[test]
+    fn given_grid_when_displayed_then_it_renders_one_line_per_row() {
+        let mut grid = Grid::new(Column::new(3), Row::new(2));
+        grid.populate(Column::new(1), Row::new(0)).unwrap();
+
+        assert_eq!(grid.to_string(), ".O.\n...\n");
+    }
+
+    #[test]
+    fn given_grid_when_rendered_with_custom_glyphs_then_they_are_used() {
+        let mut grid = Grid::new(Column::new(2), Row::new(1));
+        grid.populate(Column::new(0), Row::new(0)).unwrap();
+
+        let renderer = Renderer::new('#', ' ');
+
+        assert_eq!(grid.render(&renderer), "# \n");
+    }
+
+    #[test]
+    fn given_grid_when_rendered_as_table_then_it_is_boxed() {
+        let mut grid = Grid::new(Column::new(2), Row::new(1));
+        grid.populate(Column::new(0), Row::new(0)).unwrap();
+
+        assert_eq!(grid.to_table(), "+--+\n|O.|\n+--+\n");
+    }
+
+    #[test]
+    fn given_grid_when_region_rendered_then_only_that_rectangle_is_printed() {
+        let mut grid = Grid::new(Column::new(4), Row::new(4));
+        grid.populate(Column::new(2), Row::new(1)).unwrap();
+
+        let region = Rect::new(1, 1, 2, 2);
+
+        assert_eq!(grid.render_region(region, &Renderer::default()), ".O\n..\n");
+    }
+
+    #[test]
+    fn given_empty_sparse_grid_when_displayed_then_it_renders_nothing() {
+        let grid = SparseGrid::new();
+
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn given_sparse_grid_when_displayed_then_it_is_bounded_by_its_live_cells() {
+        let mut grid = SparseGrid::new();
+        grid.populate(Coord::new(0, 0));
+        grid.populate(Coord::new(1, 1));
+
+        assert_eq!(grid.to_string(), "O.\n.O\n");
+    }
+
     #[test]
     fn given_new_grid_with_isolated_cells_when_next_they_die() {
         let mut grid = Grid::new(Column::new(20), Row::new(20));
@@ -319,6 +1239,186 @@ mod tests {
         assert!(cell_1.is_empty());
         assert!(cell_2.is_empty());
     }
+
+    #[test]
+    fn can_parse_conway_rulestring() {
+        let rule = Rule::parse("B3/S23").unwrap();
+
+        assert_eq!(rule.birth, HashSet::from([3]));
+        assert_eq!(rule.survival, HashSet::from([2, 3]));
+    }
+
+    #[test]
+    fn can_parse_highlife_rulestring() {
+        let rule = Rule::parse("B36/S23").unwrap();
+
+        assert_eq!(rule.birth, HashSet::from([3, 6]));
+        assert_eq!(rule.survival, HashSet::from([2, 3]));
+    }
+
+    #[test]
+    fn rejects_malformed_rulestring() {
+        assert!(Rule::parse("B3S23").is_err());
+        assert!(Rule::parse("X3/S23").is_err());
+    }
+
+    #[test]
+    fn given_blinker_when_next_then_it_oscillates() {
+        let mut grid = Grid::new(Column::new(5), Row::new(5));
+
+        // Vertical blinker centred at (2, *).
+        grid.populate(Column::new(2), Row::new(1)).unwrap();
+        grid.populate(Column::new(2), Row::new(2)).unwrap();
+        grid.populate(Column::new(2), Row::new(3)).unwrap();
+
+        grid.next();
+
+        assert!(grid.cell(Column::new(1), Row::new(2)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(2)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(3), Row::new(2)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(1)).unwrap().is_empty());
+        assert!(grid.cell(Column::new(2), Row::new(3)).unwrap().is_empty());
+
+        grid.next();
+
+        assert!(grid.cell(Column::new(2), Row::new(1)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(2)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(3)).unwrap().is_populated());
+    }
+
+    #[test]
+    fn given_block_when_next_then_it_is_still_life() {
+        let mut grid = Grid::new(Column::new(5), Row::new(5));
+
+        grid.populate(Column::new(1), Row::new(1)).unwrap();
+        grid.populate(Column::new(2), Row::new(1)).unwrap();
+        grid.populate(Column::new(1), Row::new(2)).unwrap();
+        grid.populate(Column::new(2), Row::new(2)).unwrap();
+
+        grid.next();
+
+        assert!(grid.cell(Column::new(1), Row::new(1)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(1)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(1), Row::new(2)).unwrap().is_populated());
+        assert!(grid.cell(Column::new(2), Row::new(2)).unwrap().is_populated());
+    }
+
+    #[test]
+    fn given_highlife_rule_when_next_with_then_birth_on_six_neighbours() {
+        let mut grid = Grid::new(Column::new(5), Row::new(5));
+        let rule = Rule::parse("B36/S23").unwrap();
+
+        // Surround (2, 2) with exactly 6 live neighbours; it should stay empty
+        // under Conway but become populated under HighLife.
+        for (column, row) in [(1, 1), (2, 1), (3, 1), (1, 2), (3, 2), (1, 3)] {
+            grid.populate(Column::new(column), Row::new(row)).unwrap();
+        }
+
+        grid.next_with(&rule);
+
+        assert!(grid.cell(Column::new(2), Row::new(2)).unwrap().is_populated());
+    }
+
+    #[test]
+    fn given_toroidal_topology_when_neighbour_wraps_past_edge_then_it_is_counted() {
+        let mut grid = Grid::new(Column::new(3), Row::new(3));
+        grid.populate(Column::new(2), Row::new(1)).unwrap();
+
+        let bounded_count = grid.neighbour_count(Column::new(0), Row::new(1), Topology::Bounded);
+        let toroidal_count = grid.neighbour_count(Column::new(0), Row::new(1), Topology::Toroidal);
+
+        assert_eq!(bounded_count, 0);
+        assert_eq!(toroidal_count, 1);
+    }
+
+    #[test]
+    fn given_line_of_sight_topology_when_first_populated_cell_is_beyond_a_gap_then_it_is_counted() {
+        let mut grid = Grid::new(Column::new(5), Row::new(5));
+        grid.populate(Column::new(4), Row::new(2)).unwrap();
+
+        let bounded_count = grid.neighbour_count(Column::new(2), Row::new(2), Topology::Bounded);
+        let line_of_sight_count =
+            grid.neighbour_count(Column::new(2), Row::new(2), Topology::LineOfSight);
+
+        assert_eq!(bounded_count, 0);
+        assert_eq!(line_of_sight_count, 1);
+    }
+
+    #[test]
+    fn given_line_of_sight_topology_when_nothing_in_view_then_it_stops_at_the_edge() {
+        let grid = Grid::new(Column::new(5), Row::new(5));
+
+        assert_eq!(
+            grid.neighbour_count(Column::new(0), Row::new(0), Topology::LineOfSight),
+            0
+        );
+    }
+
+    #[test]
+    fn given_sparse_blinker_when_next_then_it_oscillates() {
+        let mut grid = SparseGrid::new();
+
+        grid.populate(Coord::new(2, 1));
+        grid.populate(Coord::new(2, 2));
+        grid.populate(Coord::new(2, 3));
+
+        grid.next();
+
+        assert!(grid.is_populated(Coord::new(1, 2)));
+        assert!(grid.is_populated(Coord::new(2, 2)));
+        assert!(grid.is_populated(Coord::new(3, 2)));
+        assert!(!grid.is_populated(Coord::new(2, 1)));
+        assert!(!grid.is_populated(Coord::new(2, 3)));
+        assert_eq!(grid.live_count(), 3);
+    }
+
+    #[test]
+    fn given_sparse_isolated_cell_and_a_rule_that_survives_on_zero_then_it_survives() {
+        let mut grid = SparseGrid::new();
+        grid.populate(Coord::new(0, 0));
+
+        grid.next_with(&Rule::parse("B3/S023").unwrap());
+
+        assert!(grid.is_populated(Coord::new(0, 0)));
+        assert_eq!(grid.live_count(), 1);
+    }
+
+    #[test]
+    fn given_sparse_grid_with_negative_coordinates_when_next_then_pattern_is_not_clipped() {
+        let mut grid = SparseGrid::new();
+
+        // A blinker straddling the origin, unlike a dense Grid, is never
+        // clipped by a `0` edge.
+        grid.populate(Coord::new(-1, 0));
+        grid.populate(Coord::new(0, 0));
+        grid.populate(Coord::new(1, 0));
+
+        grid.next();
+
+        assert!(grid.is_populated(Coord::new(0, -1)));
+        assert!(grid.is_populated(Coord::new(0, 0)));
+        assert!(grid.is_populated(Coord::new(0, 1)));
+    }
+
+    #[test]
+    fn given_sparse_grid_when_empty_then_bounding_box_is_none() {
+        let grid = SparseGrid::new();
+
+        assert_eq!(grid.bounding_box(), None);
+    }
+
+    #[test]
+    fn given_sparse_grid_when_populated_then_bounding_box_spans_live_cells() {
+        let mut grid = SparseGrid::new();
+
+        grid.populate(Coord::new(-2, 5));
+        grid.populate(Coord::new(3, -1));
+
+        assert_eq!(
+            grid.bounding_box(),
+            Some((Coord::new(-2, -1), Coord::new(3, 5)))
+        );
+    }
 }
 
 #[cfg(test)]